@@ -3,11 +3,32 @@
 use crate::storage::mvcc::{Result, TxnCommitRecord};
 use rfstore::{UserMeta, EXTRA_CF, LOCK_CF, WRITE_CF};
 use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 use txn_types::{Key, Lock, OldValue, TimeStamp, Value, Write, WriteType};
 
+/// Number of times a fetch whose result fails the integrity check (a corrupt block from the
+/// backing object store) is retried before the last, possibly-bad, result is handed back
+/// as-is. An empty result is taken as an absent key and is not retried.
+const MAX_FETCH_RETRIES: u32 = 3;
+const FETCH_RETRY_BACKOFF: Duration = Duration::from_millis(10);
+
+/// Counts how often `CloudReader` had to retry a remote fetch, broken down by why the first
+/// attempt didn't stick. `tikv_kv::Statistics` has no room for cloud-backend-specific counters,
+/// so these live alongside `CloudReader::statistics` instead of inside it. Note that this means
+/// `retry_stats` is NOT visible to anything that only reads `CloudReader::statistics`: a caller
+/// that wants these counters on a metrics/dashboard path must read this field explicitly, since
+/// nothing currently wires it into `statistics` or any external reporting.
+#[derive(Default, Clone, Copy)]
+pub struct FetchRetryStats {
+    pub retries: u64,
+    pub checksum_mismatches: u64,
+}
+
 pub struct CloudReader {
     snapshot: Arc<kvengine::SnapAccess>,
     pub statistics: tikv_kv::Statistics,
+    pub retry_stats: FetchRetryStats,
 }
 
 impl CloudReader {
@@ -15,7 +36,81 @@ impl CloudReader {
         Self {
             snapshot,
             statistics: tikv_kv::Statistics::default(),
+            retry_stats: FetchRetryStats::default(),
+        }
+    }
+
+    /// Verifies `item`'s integrity for `cf`. `WRITE_CF`/`EXTRA_CF` items carry a checksum in
+    /// their `UserMeta` (a field added alongside `gc_fence` on the `rfstore` write-encoding
+    /// path); an item with no user meta there, or a zero checksum, is assumed valid. `LOCK_CF`
+    /// entries carry no `UserMeta` at all, so a missing lock (an empty value) is legitimate,
+    /// but a non-empty value that fails to parse as a `Lock` is a genuine corruption signal.
+    fn item_checksum_valid(cf: usize, item: &kvengine::Item) -> bool {
+        if cf == LOCK_CF {
+            return item.value_len() == 0 || Lock::parse(item.get_value()).is_ok();
+        }
+        if item.user_meta_len() == 0 {
+            return true;
+        }
+        let user_meta = UserMeta::from_slice(item.user_meta());
+        user_meta.checksum == 0 || crc32fast::hash(item.get_value()) == user_meta.checksum
+    }
+
+    /// Runs `fetch` (a `snapshot.get`, or a read of an iterator's current item) against `cf`,
+    /// retrying with exponential backoff while the result fails `item_checksum_valid`. This is
+    /// the resilience layer for a backend that may transiently return a corrupt block; a
+    /// missing block that comes back as an empty item passes the check and is not retried.
+    fn fetch_with_retry<F>(&mut self, cf: usize, mut fetch: F) -> kvengine::Item
+    where
+        F: FnMut() -> kvengine::Item,
+    {
+        let mut item = fetch();
+        let mut backoff = FETCH_RETRY_BACKOFF;
+        for _ in 0..MAX_FETCH_RETRIES {
+            if Self::item_checksum_valid(cf, &item) {
+                return item;
+            }
+            self.retry_stats.checksum_mismatches += 1;
+            self.retry_stats.retries += 1;
+            thread::sleep(backoff);
+            backoff *= 2;
+            item = fetch();
+        }
+        item
+    }
+
+    /// Pure decision, shared by every WRITE_CF version walk (`scan`, `seek_write_in_iter`): is
+    /// this version — identified by its `commit_ts`/`start_ts`/`gc_fence`, `has_value` telling
+    /// `Put` from `Delete` — the write visible at `ts`, honoring `gc_fence_limit`? Value bytes
+    /// are deliberately not touched here so this can run before they're fetched from the
+    /// remote backend; `short_value` on the returned `Write` is left `None` for the caller to
+    /// fill in when `write_type` is `Put`. Returns `None` both when this version isn't yet
+    /// visible at `ts` and when it's GC-fenced out, since either way the caller's next step is
+    /// the same: keep walking towards older versions of the same key.
+    fn resolve_version(
+        commit_ts: u64,
+        start_ts: u64,
+        gc_fence: u64,
+        has_value: bool,
+        ts: u64,
+        gc_fence_limit: Option<TimeStamp>,
+    ) -> Option<(TimeStamp, Write)> {
+        if commit_ts > ts {
+            return None;
+        }
+        let write_type = if has_value {
+            WriteType::Put
+        } else {
+            WriteType::Delete
+        };
+        let mut write = Write::new(write_type, TimeStamp::new(start_ts), None);
+        if gc_fence != 0 {
+            write = write.set_gc_fence(TimeStamp::new(gc_fence));
         }
+        if !write.check_gc_fence_as_latest_version(gc_fence_limit) {
+            return None;
+        }
+        Some((TimeStamp::new(commit_ts), write))
     }
 
     fn get_commit_by_item(item: &kvengine::Item, start_ts: TimeStamp) -> Option<TxnCommitRecord> {
@@ -41,7 +136,8 @@ impl CloudReader {
         start_ts: TimeStamp,
     ) -> Result<TxnCommitRecord> {
         let raw_key = key.to_raw()?;
-        let item = self.snapshot.get(WRITE_CF, &raw_key, 0);
+        let snapshot = self.snapshot.clone();
+        let item = self.fetch_with_retry(WRITE_CF, || snapshot.get(WRITE_CF, &raw_key, 0));
         if item.user_meta_len() > 0 {
             if let Some(record) = Self::get_commit_by_item(&item, start_ts) {
                 return Ok(record);
@@ -54,14 +150,16 @@ impl CloudReader {
             if key != raw_key {
                 break;
             }
-            if let Some(record) = Self::get_commit_by_item(&data_iter.item(), start_ts) {
+            let item = self.fetch_with_retry(WRITE_CF, || data_iter.item());
+            if let Some(record) = Self::get_commit_by_item(&item, start_ts) {
                 return Ok(record);
             }
             data_iter.next();
         }
         let rollback_key =
             rfstore::mvcc::encode_extra_txn_status_key(&raw_key, start_ts.into_inner());
-        let item = self.snapshot.get(EXTRA_CF, &rollback_key, 0);
+        let snapshot = self.snapshot.clone();
+        let item = self.fetch_with_retry(EXTRA_CF, || snapshot.get(EXTRA_CF, &rollback_key, 0));
         if item.value_len() == 0 {
             return Ok(TxnCommitRecord::None {
                 overlapped_write: None,
@@ -80,9 +178,48 @@ impl CloudReader {
         })
     }
 
-    pub fn load_lock(&self, key: &Key) -> Result<Option<Lock>> {
+    /// Batched form of `get_txn_commit_record`: `keys` must be sorted ascending. Reuses a
+    /// single forward `WRITE_CF` iterator across all of `keys` instead of re-seeking per key,
+    /// falling back to `get_txn_commit_record` (including its `EXTRA_CF` rollback lookup) for
+    /// any key whose commit record isn't found in the shared scan.
+    pub fn batch_get_txn_commit_record(
+        &mut self,
+        keys: &[Key],
+        start_ts: TimeStamp,
+    ) -> Result<Vec<TxnCommitRecord>> {
+        let mut records = Vec::with_capacity(keys.len());
+        let mut data_iter = self.snapshot.new_iterator(WRITE_CF, false, true);
+        let mut started = false;
+        for key in keys {
+            let raw_key = key.to_raw()?;
+            if !started {
+                data_iter.seek(&raw_key);
+                started = true;
+            } else {
+                while data_iter.valid() && data_iter.key() < raw_key.as_slice() {
+                    data_iter.next();
+                }
+            }
+            let mut record = None;
+            while data_iter.valid() && data_iter.key() == raw_key.as_slice() {
+                if record.is_none() {
+                    let item = self.fetch_with_retry(WRITE_CF, || data_iter.item());
+                    record = Self::get_commit_by_item(&item, start_ts);
+                }
+                data_iter.next();
+            }
+            records.push(match record {
+                Some(record) => record,
+                None => self.get_txn_commit_record(key, start_ts)?,
+            });
+        }
+        Ok(records)
+    }
+
+    pub fn load_lock(&mut self, key: &Key) -> Result<Option<Lock>> {
         let raw_key = key.to_raw().unwrap();
-        let item = self.snapshot.get(LOCK_CF, &raw_key, 0);
+        let snapshot = self.snapshot.clone();
+        let item = self.fetch_with_retry(LOCK_CF, || snapshot.get(LOCK_CF, &raw_key, 0));
         if item.value_len() == 0 {
             return Ok(None);
         }
@@ -90,33 +227,84 @@ impl CloudReader {
         return Ok(Some(lock));
     }
 
+    /// Batched form of `load_lock`: `keys` must be sorted ascending. Advances one shared
+    /// forward `LOCK_CF` iterator in step with `keys` instead of re-seeking per key; a key can
+    /// only miss the iterator (and fall back to `load_lock`'s own point lookup) by appearing
+    /// out of order or duplicated, since it never legitimately has more than one LOCK_CF entry.
+    pub fn batch_load_lock(&mut self, keys: &[Key]) -> Result<Vec<Option<Lock>>> {
+        let mut locks = Vec::with_capacity(keys.len());
+        let mut lock_iter = self.snapshot.new_iterator(LOCK_CF, false, false);
+        let mut prev_raw_key: Option<Vec<u8>> = None;
+        for key in keys {
+            let raw_key = key.to_raw()?;
+            if prev_raw_key.as_ref().map_or(false, |prev| raw_key <= *prev) {
+                locks.push(self.load_lock(key)?);
+                continue;
+            }
+            if prev_raw_key.is_none() {
+                lock_iter.seek(&raw_key);
+            } else {
+                while lock_iter.valid() && lock_iter.key() < raw_key.as_slice() {
+                    lock_iter.next();
+                }
+            }
+            if lock_iter.valid() && lock_iter.key() == raw_key.as_slice() {
+                let item = self.fetch_with_retry(LOCK_CF, || lock_iter.item());
+                locks.push(Some(Lock::parse(item.get_value())?));
+                lock_iter.next();
+            } else {
+                locks.push(None);
+            }
+            prev_raw_key = Some(raw_key);
+        }
+        Ok(locks)
+    }
+
     pub fn get(
         &mut self,
         key: &Key,
         ts: TimeStamp,
-        _gc_fence_limit: Option<TimeStamp>,
+        gc_fence_limit: Option<TimeStamp>,
     ) -> Result<Option<Value>> {
-        let raw_key = key.to_raw()?;
-        let item = self.snapshot.get(WRITE_CF, &raw_key, ts.into_inner());
-        if item.value_len() > 0 {
-            return Ok(Some(item.get_value().to_vec()));
+        match self.get_write(key, ts, gc_fence_limit)? {
+            Some(write) if write.write_type == WriteType::Put => {
+                Ok(Some(write.short_value.unwrap_or_default()))
+            }
+            _ => Ok(None),
         }
-        return Ok(None);
     }
 
+    /// Like `seek_write`, but also enforces `gc_fence_limit`: a write that was GC-fenced by an
+    /// overlapping rollback/lock is only honored as the latest version when `gc_fence_limit`
+    /// matches the fence exactly, otherwise older versions are tried in turn.
     pub fn get_write(
         &mut self,
         key: &Key,
         ts: TimeStamp,
-        _gc_fence_limit: Option<TimeStamp>,
+        gc_fence_limit: Option<TimeStamp>,
     ) -> Result<Option<Write>> {
-        self.seek_write(key, ts)
-            .map(|opt| opt.map(|(_, write)| write))
+        let mut probe_ts = ts;
+        loop {
+            let (commit_ts, write) = match self.seek_write(key, probe_ts)? {
+                Some(found) => found,
+                None => return Ok(None),
+            };
+            if write.check_gc_fence_as_latest_version(gc_fence_limit) {
+                return Ok(Some(write));
+            }
+            if commit_ts.is_zero() {
+                return Ok(None);
+            }
+            probe_ts = commit_ts.prev();
+        }
     }
 
     pub fn seek_write(&mut self, key: &Key, ts: TimeStamp) -> Result<Option<(TimeStamp, Write)>> {
         let raw_key = key.to_raw()?;
-        let item = self.snapshot.get(WRITE_CF, &raw_key, ts.into_inner());
+        let snapshot = self.snapshot.clone();
+        let item = self.fetch_with_retry(WRITE_CF, || {
+            snapshot.get(WRITE_CF, &raw_key, ts.into_inner())
+        });
         if item.user_meta_len() > 0 {
             let user_meta = UserMeta::from_slice(item.user_meta());
             let write_type: WriteType;
@@ -128,7 +316,13 @@ impl CloudReader {
                 write_type = WriteType::Put;
                 short_value = Some(item.get_value().to_vec())
             }
-            let write = Write::new(write_type, TimeStamp::new(user_meta.start_ts), short_value);
+            let mut write = Write::new(write_type, TimeStamp::new(user_meta.start_ts), short_value);
+            // `gc_fence` is carried in `UserMeta` by the `rfstore` write-encoding path; a
+            // non-zero value means this write was fenced by an overlapping rollback/lock and
+            // `get_write` must validate it against the caller's `gc_fence_limit` before use.
+            if user_meta.gc_fence != 0 {
+                write = write.set_gc_fence(TimeStamp::new(user_meta.gc_fence));
+            }
             return Ok(Some((
                 TimeStamp::new(user_meta.commit_ts),
                 write.to_owned(),
@@ -137,19 +331,222 @@ impl CloudReader {
         return Ok(None);
     }
 
+    /// Batched form of `get`: `keys` must be sorted ascending, with `gc_fence_limits` the same
+    /// length and aligned index-for-index with `keys`, since the fence limit is tied to each
+    /// key's own lock/for-update context rather than shared across a batch. Reuses a single
+    /// forward `WRITE_CF` iterator across all of `keys` instead of one `snapshot.get` per key,
+    /// falling back to `get_write` for any key the iterator has already stepped past.
+    pub fn multi_get(
+        &mut self,
+        keys: &[Key],
+        ts: TimeStamp,
+        gc_fence_limits: &[Option<TimeStamp>],
+    ) -> Result<Vec<Option<Value>>> {
+        assert_eq!(keys.len(), gc_fence_limits.len());
+        let mut values = Vec::with_capacity(keys.len());
+        let mut data_iter = self.snapshot.new_iterator(WRITE_CF, false, true);
+        let mut started = false;
+        for (key, &gc_fence_limit) in keys.iter().zip(gc_fence_limits) {
+            let raw_key = key.to_raw()?;
+            if !started {
+                data_iter.seek(&raw_key);
+                started = true;
+            } else {
+                while data_iter.valid() && data_iter.key() < raw_key.as_slice() {
+                    data_iter.next();
+                }
+            }
+            let write = if data_iter.valid() && data_iter.key() == raw_key.as_slice() {
+                self.seek_write_in_iter(&mut data_iter, &raw_key, ts, gc_fence_limit)
+                    .map(|(_, write)| write)
+            } else {
+                self.get_write(key, ts, gc_fence_limit)?
+            };
+            values.push(match write {
+                Some(write) if write.write_type == WriteType::Put => write.short_value,
+                _ => None,
+            });
+        }
+        Ok(values)
+    }
+
+    /// Resolves the write visible at `ts` (honoring `gc_fence_limit`, trying progressively
+    /// older versions of the same key when a newer one is fenced out) from a `WRITE_CF`
+    /// iterator already positioned at `raw_key`. Leaves the iterator positioned at the first
+    /// item belonging to a different key, so callers can keep walking forward to the next key
+    /// in a sorted batch.
+    fn seek_write_in_iter(
+        &mut self,
+        data_iter: &mut kvengine::Iterator,
+        raw_key: &[u8],
+        ts: TimeStamp,
+        gc_fence_limit: Option<TimeStamp>,
+    ) -> Option<(TimeStamp, Write)> {
+        let mut result = None;
+        while data_iter.valid() && data_iter.key() == raw_key {
+            if result.is_some() {
+                data_iter.next();
+                continue;
+            }
+            let item = self.fetch_with_retry(WRITE_CF, || data_iter.item());
+            if item.user_meta_len() == 0 {
+                data_iter.next();
+                continue;
+            }
+            let user_meta = UserMeta::from_slice(item.user_meta());
+            result = Self::resolve_version(
+                user_meta.commit_ts,
+                user_meta.start_ts,
+                user_meta.gc_fence,
+                item.value_len() > 0,
+                ts.into_inner(),
+                gc_fence_limit,
+            )
+            .map(|(commit_ts, mut write)| {
+                if write.write_type == WriteType::Put {
+                    write.short_value = Some(item.get_value().to_vec());
+                }
+                (commit_ts, write)
+            });
+            data_iter.next();
+        }
+        result
+    }
+
     #[inline(always)]
-    pub fn get_old_value(&mut self, prev_write: Option<Write>) -> Result<OldValue> {
+    pub fn get_old_value(
+        &mut self,
+        prev_write: Option<Write>,
+        gc_fence_limit: Option<TimeStamp>,
+    ) -> Result<OldValue> {
         if let Some(write) = prev_write {
             if write.write_type == WriteType::Delete {
                 return Ok(OldValue::None);
             }
             // Locks and Rolbacks are stored in extra CF, will not be seeked by seek_write.
             assert_eq!(write.write_type, WriteType::Put);
+            if !write.check_gc_fence_as_latest_version(gc_fence_limit) {
+                return Ok(OldValue::None);
+            }
             return Ok(OldValue::value(write.short_value.unwrap()));
         }
         return Ok(OldValue::None);
     }
 
+    /// Scan the values visible at `ts` over `[start, end)`, in ascending key order, or in
+    /// descending key order when `reverse` is set (in which case `start`/`end` still denote the
+    /// inclusive-lower/exclusive-upper bounds of the range, not the scan direction). Honors
+    /// `gc_fence_limit` the same way `get`/`get_write` do, so a range scan can't surface a
+    /// value that a point read of the same key at the same `ts` would hide.
+    ///
+    /// At most `limit` key-value pairs are returned. If `limit` is set to `0`, it means
+    /// unlimited.
+    ///
+    /// The return type is `(pairs, is_remain)`. `is_remain` indicates whether there MAY be
+    /// remaining pairs that can be scanned.
+    pub fn scan(
+        &mut self,
+        start: Option<&Key>,
+        end: Option<&Key>,
+        ts: TimeStamp,
+        limit: usize,
+        reverse: bool,
+        gc_fence_limit: Option<TimeStamp>,
+    ) -> Result<(Vec<(Key, Value)>, bool)> {
+        let mut pairs = vec![];
+        let raw_start = start.map(|k| k.to_raw()).transpose()?;
+        let raw_end = end.map(|k| k.to_raw()).transpose()?;
+
+        let mut data_iter = self.snapshot.new_iterator(WRITE_CF, reverse, true);
+        if reverse {
+            match &raw_end {
+                Some(raw_end) => {
+                    data_iter.seek(raw_end);
+                    // `end` is exclusive, and a key may have more than one committed version
+                    // in WRITE_CF, so every entry for `raw_end` must be skipped, not just the
+                    // first one, or a later version of the excluded key would be mistaken for
+                    // an in-range key.
+                    while data_iter.valid() && data_iter.key() == raw_end.as_slice() {
+                        data_iter.next();
+                    }
+                }
+                None => data_iter.rewind(),
+            }
+        } else {
+            match &raw_start {
+                Some(raw_start) => data_iter.seek(raw_start),
+                None => data_iter.rewind(),
+            }
+        }
+
+        while data_iter.valid() {
+            let raw_key = data_iter.key().to_vec();
+            if reverse {
+                if let Some(raw_start) = &raw_start {
+                    if raw_key.as_slice() < raw_start.as_slice() {
+                        return Ok((pairs, false));
+                    }
+                }
+            } else if let Some(raw_end) = &raw_end {
+                if raw_key.as_slice() >= raw_end.as_slice() {
+                    return Ok((pairs, false));
+                }
+            }
+
+            // Walk the versions of this key, newest first, looking for the one visible at `ts`
+            // (trying progressively older versions when a newer one is GC-fenced out).
+            let mut visible_value = None;
+            while data_iter.valid() && data_iter.key() == raw_key.as_slice() {
+                let item = self.fetch_with_retry(WRITE_CF, || data_iter.item());
+                self.statistics.write.processed_keys += 1;
+                self.statistics.write.flow_stats.read_bytes +=
+                    item.value_len() + item.user_meta_len();
+                self.statistics.write.flow_stats.read_keys += 1;
+                if item.user_meta_len() == 0 {
+                    data_iter.next();
+                    continue;
+                }
+                let user_meta = UserMeta::from_slice(item.user_meta());
+                match Self::resolve_version(
+                    user_meta.commit_ts,
+                    user_meta.start_ts,
+                    user_meta.gc_fence,
+                    item.value_len() > 0,
+                    ts.into_inner(),
+                    gc_fence_limit,
+                ) {
+                    Some((_, write)) if write.write_type == WriteType::Put => {
+                        visible_value = Some(item.get_value().to_vec());
+                        data_iter.next();
+                        break;
+                    }
+                    Some(_) => {
+                        // Newest visible-and-unfenced version at `ts` is a `Delete`: the key
+                        // has no value at `ts`, not that we should keep looking at older
+                        // versions.
+                        data_iter.next();
+                        break;
+                    }
+                    None => {
+                        data_iter.next();
+                    }
+                }
+            }
+            // Skip any remaining older versions of this key so we land on the next distinct key.
+            while data_iter.valid() && data_iter.key() == raw_key.as_slice() {
+                data_iter.next();
+            }
+
+            if let Some(value) = visible_value {
+                pairs.push((Key::from_raw(&raw_key), value));
+                if limit > 0 && pairs.len() == limit {
+                    return Ok((pairs, true));
+                }
+            }
+        }
+        Ok((pairs, false))
+    }
+
     /// Scan locks that satisfies `filter(lock)` returns true, from the given start key `start`.
     /// At most `limit` locks will be returned. If `limit` is set to `0`, it means unlimited.
     ///
@@ -180,7 +577,7 @@ impl CloudReader {
                     return Ok((locks, false));
                 }
             }
-            let item = lock_iter.item();
+            let item = self.fetch_with_retry(LOCK_CF, || lock_iter.item());
             let lock = Lock::parse(item.get_value())?;
             if filter(&lock) {
                 locks.push((key, lock));
@@ -193,3 +590,47 @@ impl CloudReader {
         Ok((locks, false))
     }
 }
+
+// `scan`, `seek_write_in_iter`, `multi_get` and friends all walk a live `kvengine::SnapAccess`
+// iterator, which isn't vendored in this tree and so can't be constructed or driven from a
+// test here. What can be tested without it is `resolve_version`, the pure per-version
+// visibility/gc-fence decision every one of those walks funnels through — including the
+// gc-fence check and the Delete-tombstone handling that those walks depend on.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_version_skips_versions_newer_than_ts() {
+        assert!(CloudReader::resolve_version(20, 20, 0, true, 10, None).is_none());
+    }
+
+    #[test]
+    fn resolve_version_returns_the_version_committed_at_ts() {
+        let (commit_ts, write) = CloudReader::resolve_version(10, 10, 0, true, 10, None).unwrap();
+        assert_eq!(commit_ts, TimeStamp::new(10));
+        assert_eq!(write.write_type, WriteType::Put);
+    }
+
+    #[test]
+    fn resolve_version_delete_is_visible_but_carries_no_value() {
+        let (_, write) = CloudReader::resolve_version(5, 5, 0, false, 10, None).unwrap();
+        assert_eq!(write.write_type, WriteType::Delete);
+        assert_eq!(write.short_value, None);
+    }
+
+    #[test]
+    fn resolve_version_unfenced_write_ignores_gc_fence_limit() {
+        assert!(CloudReader::resolve_version(5, 5, 0, true, 10, Some(TimeStamp::new(123))).is_some());
+    }
+
+    #[test]
+    fn resolve_version_fenced_write_requires_exact_gc_fence_limit_match() {
+        // No gc_fence_limit given for a fenced write: it isn't the latest version.
+        assert!(CloudReader::resolve_version(5, 5, 8, true, 10, None).is_none());
+        // gc_fence_limit matches the fence exactly: usable as the latest version.
+        assert!(CloudReader::resolve_version(5, 5, 8, true, 10, Some(TimeStamp::new(8))).is_some());
+        // gc_fence_limit present but doesn't match the fence: still not usable.
+        assert!(CloudReader::resolve_version(5, 5, 8, true, 10, Some(TimeStamp::new(9))).is_none());
+    }
+}